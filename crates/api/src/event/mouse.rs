@@ -1,4 +1,8 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::rc::Rc;
+use std::time::Instant;
 
 use crate::{
     prelude::*,
@@ -26,6 +30,66 @@ pub fn check_mouse_condition(mouse_position: Point, widget: &WidgetContainer<'_>
     rect.contains((mouse_position.x, mouse_position.y))
 }
 
+/// Compares the previous and current `check_mouse_condition` result for a widget and
+/// reports the hover transition, if any, that a `MouseMoveEvent` should raise.
+///
+/// Used to drive `MouseEnterEvent`/`MouseLeaveEvent`: the event system stores `was_inside`
+/// per widget and calls this on every move, updating the stored flag to `is_inside`
+/// afterwards.
+pub fn check_mouse_hover_transition(was_inside: bool, is_inside: bool) -> Option<bool> {
+    match (was_inside, is_inside) {
+        (false, true) => Some(true),
+        (true, false) => Some(false),
+        _ => None,
+    }
+}
+
+/// Tracks, per widget, whether the pointer was inside its bounds on the previous
+/// `MouseMoveEvent`, and turns `check_mouse_hover_transition` into the events the window
+/// adapter should raise.
+///
+/// The window adapter owns one `HoverTracker` per window and calls `moved` with the
+/// up-to-date `check_mouse_condition` result for every widget hit-tested on a move, and
+/// `clear` when the pointer leaves the window or the window loses focus.
+#[derive(Debug, Default, Clone)]
+pub struct HoverTracker {
+    inside: HashMap<Entity, bool>,
+}
+
+impl HoverTracker {
+    /// Creates an empty hover tracker.
+    pub fn new() -> Self {
+        HoverTracker {
+            inside: HashMap::new(),
+        }
+    }
+
+    /// Updates the stored hover state of `widget` and returns `true` if a `MouseEnterEvent`
+    /// should be raised, `false` if a `MouseLeaveEvent` should be raised, or `None` if the
+    /// hover state did not change.
+    pub fn moved(&mut self, widget: Entity, is_inside: bool) -> Option<bool> {
+        let was_inside = self.inside.get(&widget).copied().unwrap_or(false);
+        let transition = check_mouse_hover_transition(was_inside, is_inside);
+        self.inside.insert(widget, is_inside);
+        transition
+    }
+
+    /// Clears the hover state of every widget, returning the widgets that were hovered and
+    /// therefore need a `MouseLeaveEvent` raised for them. Call this when the window loses
+    /// focus or the pointer leaves the window entirely.
+    pub fn clear(&mut self) -> Vec<Entity> {
+        let left: Vec<Entity> = self
+            .inside
+            .iter()
+            .filter(|(_, inside)| **inside)
+            .map(|(widget, _)| *widget)
+            .collect();
+
+        self.inside.clear();
+        left
+    }
+}
+
 /// `MouseMoveEvent` indicates if the mouse position is changed on the window.
 #[derive(Event)]
 pub struct MouseMoveEvent {
@@ -34,6 +98,40 @@ pub struct MouseMoveEvent {
 
     /// Current y position of the mouse on the window.
     pub y: f64,
+
+    /// Indicates the set of mouse buttons held down at the time of the event. Lets a move
+    /// handler implement drag-select by checking the primary button.
+    pub buttons: MouseButtons,
+
+    /// Indicates the keyboard modifier keys held down at the time of the event.
+    pub modifiers: ModifiersState,
+}
+
+/// `MouseEnterEvent` occurs when the mouse pointer crosses into a widget's bounds.
+///
+/// Raised on the false -> true transition of `check_mouse_condition` for a widget, so a
+/// hover-styled widget or a tooltip no longer has to poll the pointer position itself.
+#[derive(Event)]
+pub struct MouseEnterEvent {
+    /// Indicates the x position of the event on the window.
+    pub x: f64,
+
+    /// Indicates the y position of the event on the window.
+    pub y: f64,
+}
+
+/// `MouseLeaveEvent` occurs when the mouse pointer leaves a widget's bounds.
+///
+/// Raised on the true -> false transition of `check_mouse_condition` for a widget. It is
+/// also raised when the window loses focus or the pointer leaves the window entirely, so a
+/// widget can always rely on it to dismiss hover-driven popups and tooltips.
+#[derive(Event)]
+pub struct MouseLeaveEvent {
+    /// Indicates the x position of the event on the window.
+    pub x: f64,
+
+    /// Indicates the y position of the event on the window.
+    pub y: f64,
 }
 
 /// `ScrollEvent` occurs when the mouse wheel is moved.
@@ -43,30 +141,234 @@ pub struct ScrollEvent {
     pub delta: Point,
 }
 
+/// Platform-neutral identifier for a mouse button, independent of any particular shell
+/// backend.
+///
+/// `Primary` and `Secondary` describe the button's role (which can be swapped by the user,
+/// e.g. for left-handed configurations) rather than hard-coding "left" or "right".
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PointerButton {
+    /// The button used to select / activate. Commonly the left button.
+    Primary,
+
+    /// The button used to open context menus. Commonly the right button.
+    Secondary,
+
+    /// The middle / wheel button.
+    Auxiliary,
+
+    /// The first extra "back" button found on many mice.
+    X1,
+
+    /// The second extra "forward" button found on many mice.
+    X2,
+
+    /// Any other button reported by the shell, identified by its raw id.
+    Other(u16),
+}
+
+impl From<MouseButton> for PointerButton {
+    fn from(button: MouseButton) -> Self {
+        match button {
+            MouseButton::Left => PointerButton::Primary,
+            MouseButton::Right => PointerButton::Secondary,
+            MouseButton::Middle => PointerButton::Auxiliary,
+        }
+    }
+}
+
+/// Represents the keyboard modifier keys held down during a mouse event.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct ModifiersState {
+    /// Indicates that a shift key is pressed.
+    pub shift: bool,
+
+    /// Indicates that a ctrl key is pressed.
+    pub ctrl: bool,
+
+    /// Indicates that an alt key is pressed.
+    pub alt: bool,
+
+    /// Indicates that a meta (e.g. command / windows) key is pressed.
+    pub meta: bool,
+}
+
+/// Represents the set of mouse buttons currently held down, stored as a small bitset.
+///
+/// The event system inserts a button on `MouseDownEvent` and removes it on `MouseUpEvent`,
+/// snapshotting the set into every emitted `Mouse` so handlers can reason about drag
+/// gestures (e.g. `on_mouse_move` while the primary button is held) without tracking state
+/// themselves.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct MouseButtons(u8);
+
+impl MouseButtons {
+    /// Returns an empty set of held mouse buttons.
+    pub fn empty() -> Self {
+        MouseButtons(0)
+    }
+
+    /// Inserts `button` into the set.
+    pub fn insert(&mut self, button: PointerButton) {
+        self.0 |= Self::mask(button);
+    }
+
+    /// Removes `button` from the set.
+    pub fn remove(&mut self, button: PointerButton) {
+        self.0 &= !Self::mask(button);
+    }
+
+    /// Returns `true` if `button` is currently held down.
+    pub fn contains(&self, button: PointerButton) -> bool {
+        self.0 & Self::mask(button) != 0
+    }
+
+    fn mask(button: PointerButton) -> u8 {
+        match button {
+            PointerButton::Primary => 0b0_0001,
+            PointerButton::Secondary => 0b0_0010,
+            PointerButton::Auxiliary => 0b0_0100,
+            PointerButton::X1 => 0b0_1000,
+            PointerButton::X2 => 0b1_0000,
+            // `Other` buttons share a single bit: the set can report that *some* other
+            // button is held, but not distinguish which one.
+            PointerButton::Other(_) => 0b10_0000,
+        }
+    }
+}
+
 /// Represents the current mouse state of an mouse event.
 #[derive(Debug, Copy, Clone)]
 pub struct Mouse {
       /// Indicates the mouse button that is connected to the event.
-      pub button: MouseButton,
+      pub button: PointerButton,
 
       /// Indicates the x position of the event on the window.
       pub x: f64,
-  
+
       /// Indicates the y position of the event on the window.
       pub y: f64,
+
+      /// Indicates the set of mouse buttons held down at the time of the event.
+      pub buttons: MouseButtons,
+
+      /// Indicates the keyboard modifier keys held down at the time of the event.
+      pub modifiers: ModifiersState,
+}
+
+/// Represents the current mouse state of a `MouseMoveEvent`, without a single "the" button
+/// since none is necessarily pressed.
+#[derive(Debug, Copy, Clone)]
+pub struct MouseMove {
+    /// Indicates the x position of the event on the window.
+    pub x: f64,
+
+    /// Indicates the y position of the event on the window.
+    pub y: f64,
+
+    /// Indicates the set of mouse buttons held down at the time of the event. Lets a move
+    /// handler implement drag-select by checking the primary button.
+    pub buttons: MouseButtons,
+
+    /// Indicates the keyboard modifier keys held down at the time of the event.
+    pub modifiers: ModifiersState,
 }
 
 /// `MouseUpEvent` occurs when a mouse button is released.
 #[derive(Event)]
 pub struct MouseUpEvent {
     /// Indicates the mouse button that is released.
-    pub button: MouseButton,
+    pub button: PointerButton,
 
     /// Indicates the x position of the event on the window.
     pub x: f64,
 
     /// Indicates the y position of the event on the window.
     pub y: f64,
+
+    /// Indicates the set of mouse buttons held down at the time of the event.
+    pub buttons: MouseButtons,
+
+    /// Indicates the keyboard modifier keys held down at the time of the event.
+    pub modifiers: ModifiersState,
+}
+
+/// Default maximum time, in milliseconds, between two clicks for them to be counted as part
+/// of the same double-/triple-click sequence.
+pub const CLICK_COUNT_TIMEOUT_MILLIS: u64 = 400;
+
+/// Default maximum distance, in pixels, between two clicks for them to be counted as part of
+/// the same double-/triple-click sequence.
+pub const CLICK_COUNT_DISTANCE_THRESHOLD: f64 = 4.0;
+
+/// Decides whether a click at `position` continues the click sequence that ended at
+/// `previous_position` after `elapsed_millis`, and returns the click count to report.
+///
+/// Used by the event system on every completed click: if the click falls within
+/// `CLICK_COUNT_TIMEOUT_MILLIS` and `CLICK_COUNT_DISTANCE_THRESHOLD` of the previous one,
+/// `previous_count` is incremented, otherwise the sequence resets to `1`.
+pub fn next_click_count(
+    previous_position: Point,
+    previous_count: usize,
+    elapsed_millis: u64,
+    position: Point,
+) -> usize {
+    let dx = position.x - previous_position.x;
+    let dy = position.y - previous_position.y;
+    let distance = (dx * dx + dy * dy).sqrt();
+
+    if elapsed_millis <= CLICK_COUNT_TIMEOUT_MILLIS && distance <= CLICK_COUNT_DISTANCE_THRESHOLD {
+        previous_count + 1
+    } else {
+        1
+    }
+}
+
+/// Tracks the previous click on a widget and turns `next_click_count` into the `count` a new
+/// `ClickEvent` should carry.
+///
+/// The window adapter owns one `ClickTracker` per widget (or per window, keyed by widget)
+/// and calls `register` whenever a `MouseUpEvent` completes a click over that widget.
+#[derive(Debug, Default, Clone)]
+pub struct ClickTracker {
+    previous: Option<(Point, Instant)>,
+    count: usize,
+}
+
+impl ClickTracker {
+    /// Creates a click tracker with no previous click recorded.
+    pub fn new() -> Self {
+        ClickTracker::default()
+    }
+
+    /// Registers a completed click at `position` and returns the click count (`1` for a
+    /// single click, `2` for a double click, and so on) to put on its `ClickEvent`.
+    pub fn register(&mut self, position: Point) -> usize {
+        let now = Instant::now();
+
+        self.count = match self.previous {
+            Some((previous_position, previous_time)) => {
+                let elapsed_millis = now.duration_since(previous_time).as_millis() as u64;
+                next_click_count(previous_position, self.count, elapsed_millis, position)
+            }
+            None => 1,
+        };
+
+        self.previous = Some((position, now));
+        self.count
+    }
+}
+
+/// Represents a click together with the number of consecutive clicks (double-, triple-click,
+/// ...) it is part of.
+#[derive(Debug, Copy, Clone)]
+pub struct Click {
+    /// Indicates the x and y position of the click.
+    pub position: Point,
+
+    /// Indicates how many consecutive clicks, including this one, make up the click
+    /// sequence.
+    pub count: usize,
 }
 
 /// `ClickEvent` occurs when a user clicked on an element.
@@ -74,34 +376,388 @@ pub struct MouseUpEvent {
 pub struct ClickEvent {
     /// Indicates the x and y position of the click event.
     pub position: Point,
+
+    /// Indicates how many consecutive clicks, including this one, make up the click
+    /// sequence. See `next_click_count`.
+    pub count: usize,
 }
 
 /// `MouseDownEvent` occurs when a mouse button is pressed.
 #[derive(Event)]
 pub struct MouseDownEvent {
      /// Indicates the mouse button that is pressed.
-     pub button: MouseButton,
+     pub button: PointerButton,
 
      /// Indicates the x position of the event on the window.
      pub x: f64,
- 
+
      /// Indicates the y position of the event on the window.
      pub y: f64,
+
+     /// Indicates the set of mouse buttons held down at the time of the event.
+     pub buttons: MouseButtons,
+
+     /// Indicates the keyboard modifier keys held down at the time of the event.
+     pub modifiers: ModifiersState,
 }
 
-/// `GlobalMouseUpEvent` occurs when a mouse button is released. 
+/// `GlobalMouseUpEvent` occurs when a mouse button is released.
 ///
 /// Global events could not be handled and could be read on each state.
 #[derive(Event)]
 pub struct GlobalMouseUpEvent {
     /// Indicates the mouse button that is released.
-    pub button: MouseButton,
+    pub button: PointerButton,
 
     /// Indicates the x position of the event on the window.
     pub x: f64,
 
     /// Indicates the y position of the event on the window.
     pub y: f64,
+
+    /// Indicates the set of mouse buttons held down at the time of the event.
+    pub buttons: MouseButtons,
+
+    /// Indicates the keyboard modifier keys held down at the time of the event.
+    pub modifiers: ModifiersState,
+}
+
+/// `MouseDownOutEvent` occurs when a mouse button is pressed outside of a widget's bounds.
+///
+/// Dispatched during `MouseDownEvent` routing to every widget that registered an out-handler
+/// but whose bounds did not contain the pointer, so popups, dropdowns, and context menus can
+/// dismiss themselves when the user clicks elsewhere.
+#[derive(Event)]
+pub struct MouseDownOutEvent {
+    /// Indicates the global x position of the event on the window.
+    pub x: f64,
+
+    /// Indicates the global y position of the event on the window.
+    pub y: f64,
+}
+
+/// `ClickOutEvent` occurs when a click completes outside of a widget's bounds.
+///
+/// Dispatched alongside `MouseDownOutEvent`, once the click is known to have completed
+/// (mirroring how `ClickEvent` relates to `MouseDownEvent`/`MouseUpEvent`).
+#[derive(Event)]
+pub struct ClickOutEvent {
+    /// Indicates the global x position of the event on the window.
+    pub x: f64,
+
+    /// Indicates the global y position of the event on the window.
+    pub y: f64,
+}
+
+/// Given the hit-test result (`contains`) of every widget that registered an
+/// `on_mouse_down_out` handler, returns the widgets that should each receive a
+/// `MouseDownOutEvent` at `position` — i.e. every widget whose bounds did not contain the
+/// pointer.
+///
+/// Called by the event system while routing a `MouseDownEvent`, after `check_mouse_condition`
+/// has been evaluated for the out-handler widgets.
+pub fn mouse_down_out_targets(
+    position: Point,
+    hit_results: &[(Entity, bool)],
+) -> Vec<(Entity, MouseDownOutEvent)> {
+    hit_results
+        .iter()
+        .filter(|(_, contains)| !contains)
+        .map(|(widget, _)| {
+            (
+                *widget,
+                MouseDownOutEvent {
+                    x: position.x,
+                    y: position.y,
+                },
+            )
+        })
+        .collect()
+}
+
+/// Given the hit-test result (`contains`) of every widget that registered an `on_click_out`
+/// handler, returns the widgets that should each receive a `ClickOutEvent` at `position` —
+/// i.e. every widget whose bounds did not contain the pointer when the click completed.
+///
+/// Called by the event system once a click is known to have completed (mirroring how
+/// `ClickEvent` relates to `MouseDownEvent`/`MouseUpEvent`).
+pub fn click_out_targets(
+    position: Point,
+    hit_results: &[(Entity, bool)],
+) -> Vec<(Entity, ClickOutEvent)> {
+    hit_results
+        .iter()
+        .filter(|(_, contains)| !contains)
+        .map(|(widget, _)| {
+            (
+                *widget,
+                ClickOutEvent {
+                    x: position.x,
+                    y: position.y,
+                },
+            )
+        })
+        .collect()
+}
+
+/// Minimum distance, in pixels, the pointer must travel while the primary button is held
+/// over a draggable widget before a `DragStartEvent` is raised.
+pub const DRAG_START_THRESHOLD: f64 = 4.0;
+
+/// `DragStartEvent` occurs once the pointer has moved past `DRAG_START_THRESHOLD` while the
+/// primary button is held down over a draggable widget.
+#[derive(Event)]
+pub struct DragStartEvent {
+    /// Indicates the current x and y position of the pointer.
+    pub position: Point,
+
+    /// Indicates the widget the drag gesture originated from.
+    pub source: Entity,
+}
+
+/// `DragMoveEvent` occurs for every pointer move while a drag gesture is in progress.
+#[derive(Event)]
+pub struct DragMoveEvent {
+    /// Indicates the current x and y position of the pointer.
+    pub position: Point,
+
+    /// Indicates the widget the drag gesture originated from.
+    pub source: Entity,
+}
+
+/// `DragEndEvent` occurs when the primary button is released, ending a drag gesture.
+#[derive(Event)]
+pub struct DragEndEvent {
+    /// Indicates the x and y position of the pointer when the drag ended.
+    pub position: Point,
+
+    /// Indicates the widget the drag gesture originated from.
+    pub source: Entity,
+}
+
+/// The data carried by a `DropEvent`: either an application-defined payload set by the drag
+/// source widget, or file paths dropped onto the window from outside the application.
+#[derive(Clone)]
+pub enum DropPayload {
+    /// A type-erased payload set by the drag source widget, e.g. a list item model.
+    Data(Rc<dyn Any>),
+
+    /// One or more file paths dropped onto the window by the operating system.
+    Files(Vec<PathBuf>),
+}
+
+/// `DropEvent` occurs when a drag gesture ends over a widget that accepts the payload.
+#[derive(Event)]
+pub struct DropEvent {
+    /// Indicates the x and y position of the pointer when the drop occurred.
+    pub position: Point,
+
+    /// Indicates the widget the drag gesture originated from. Not set for file drops coming
+    /// from outside the application.
+    pub source: Option<Entity>,
+
+    /// Indicates the payload being transferred.
+    pub payload: DropPayload,
+}
+
+/// Describes the phase of an in-progress drag gesture delivered to an `on_drag` handler,
+/// together with the widget the gesture originated from.
+#[derive(Clone)]
+pub enum Drag {
+    /// The drag gesture started at the given position, originating from `source`.
+    Start { position: Point, source: Entity },
+
+    /// The pointer moved to the given position while the drag gesture, originating from
+    /// `source`, is in progress.
+    Move { position: Point, source: Entity },
+
+    /// The drag gesture, originating from `source`, ended at the given position.
+    End { position: Point, source: Entity },
+}
+
+/// Turns primary-button press/move/release over a draggable widget into the drag gesture
+/// events, applying the `DRAG_START_THRESHOLD` the request asks for.
+///
+/// The window adapter owns one `DragGestureTracker` per window: call `press` when a
+/// `MouseDownEvent` for the primary button hits a draggable widget, `moved` on every
+/// subsequent `MouseMoveEvent` while the button stays down, and `released` on the matching
+/// `MouseUpEvent`.
+#[derive(Debug, Clone, Copy)]
+pub struct DragGestureTracker {
+    state: DragGestureState,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DragGestureState {
+    Idle,
+    Pressed { source: Entity, origin: Point },
+    Dragging { source: Entity },
+}
+
+impl Default for DragGestureTracker {
+    fn default() -> Self {
+        DragGestureTracker {
+            state: DragGestureState::Idle,
+        }
+    }
+}
+
+impl DragGestureTracker {
+    /// Creates a drag gesture tracker in the idle state.
+    pub fn new() -> Self {
+        DragGestureTracker::default()
+    }
+
+    /// Arms the tracker: the primary button was pressed at `position` over `source`.
+    pub fn press(&mut self, source: Entity, position: Point) {
+        self.state = DragGestureState::Pressed {
+            source,
+            origin: position,
+        };
+    }
+
+    /// Reports the pointer having moved to `position` while the primary button is held.
+    ///
+    /// Returns a `DragStartEvent` the first time `position` moves past
+    /// `DRAG_START_THRESHOLD` from the press origin, a `DragMoveEvent` on every subsequent
+    /// move once the gesture is underway, or `None` before the threshold is crossed or when
+    /// no button is held.
+    pub fn moved(&mut self, position: Point) -> Option<DragGestureMove> {
+        match self.state {
+            DragGestureState::Idle => None,
+            DragGestureState::Pressed { source, origin } => {
+                let dx = position.x - origin.x;
+                let dy = position.y - origin.y;
+
+                if (dx * dx + dy * dy).sqrt() < DRAG_START_THRESHOLD {
+                    return None;
+                }
+
+                self.state = DragGestureState::Dragging { source };
+
+                Some(DragGestureMove::Start(DragStartEvent { position, source }))
+            }
+            DragGestureState::Dragging { source } => {
+                Some(DragGestureMove::Move(DragMoveEvent { position, source }))
+            }
+        }
+    }
+
+    /// Reports the primary button having been released at `position`, ending the gesture.
+    ///
+    /// Returns the `DragEndEvent` to dispatch if a drag was in progress (i.e. the pointer had
+    /// already crossed `DRAG_START_THRESHOLD`), or `None` if the button was released before
+    /// the gesture started (a plain click).
+    pub fn released(&mut self, position: Point) -> Option<DragEndEvent> {
+        let source = match self.state {
+            DragGestureState::Dragging { source } => Some(source),
+            _ => None,
+        };
+
+        self.state = DragGestureState::Idle;
+
+        source.map(|source| DragEndEvent { position, source })
+    }
+}
+
+/// The event a `DragGestureTracker::moved` call produces: either the gesture just started, or
+/// it was already in progress and simply moved.
+pub enum DragGestureMove {
+    /// The drag gesture just crossed `DRAG_START_THRESHOLD` and is starting.
+    Start(DragStartEvent),
+
+    /// The drag gesture is already in progress and moved further.
+    Move(DragMoveEvent),
+}
+
+/// `RawMouseMoveEvent` carries the frame-to-frame pointer delta rather than an absolute
+/// position, computed as the difference between consecutive `MouseMoveEvent` positions.
+///
+/// Raised alongside `MouseMoveEvent`, this is the right event to consume for 3D viewports,
+/// sliders, and other infinite-drag interactions where the absolute position is meaningless.
+#[derive(Event)]
+pub struct RawMouseMoveEvent {
+    /// Indicates the relative motion since the previous raw move event.
+    pub delta: Point,
+}
+
+/// `RequestPointerLockEvent` asks the event system to capture (lock) the pointer to
+/// `widget`, so motion keeps being delivered as relative deltas via `RawMouseMoveEvent` even
+/// once the pointer would leave the widget's or window's bounds. Pushed by a widget through
+/// its `StatesContext`.
+#[derive(Event)]
+pub struct RequestPointerLockEvent {
+    /// Indicates the widget the pointer should be locked to.
+    pub widget: Entity,
+}
+
+/// `RequestPointerUnlockEvent` releases a pointer lock previously requested with
+/// `RequestPointerLockEvent`.
+///
+/// Also raised implicitly by the event system on button-up, so a widget does not have to
+/// unlock manually once a drag gesture ends.
+#[derive(Event)]
+pub struct RequestPointerUnlockEvent;
+
+/// Computes frame-to-frame pointer deltas and tracks which widget, if any, currently holds
+/// the pointer lock.
+///
+/// The window adapter owns one `PointerMotionTracker` per window, calls `moved` with every
+/// absolute pointer position it receives from the shell to obtain the matching
+/// `RawMouseMoveEvent`, and calls `lock`/`unlock` in response to
+/// `RequestPointerLockEvent`/`RequestPointerUnlockEvent` (as well as `unlock` on button-up).
+/// While a widget holds the lock, motion keeps being reported as relative deltas even once
+/// the pointer would otherwise leave its bounds or the window's.
+#[derive(Debug, Clone, Copy)]
+pub struct PointerMotionTracker {
+    last_position: Option<Point>,
+    locked_widget: Option<Entity>,
+}
+
+impl Default for PointerMotionTracker {
+    fn default() -> Self {
+        PointerMotionTracker {
+            last_position: None,
+            locked_widget: None,
+        }
+    }
+}
+
+impl PointerMotionTracker {
+    /// Creates a pointer motion tracker with no prior position and no active lock.
+    pub fn new() -> Self {
+        PointerMotionTracker::default()
+    }
+
+    /// Reports the pointer having moved to the absolute `position` and returns the
+    /// `RawMouseMoveEvent` carrying the delta since the previous call. The first call after
+    /// creation reports a zero delta.
+    pub fn moved(&mut self, position: Point) -> RawMouseMoveEvent {
+        let delta = match self.last_position {
+            Some(previous) => Point::new(position.x - previous.x, position.y - previous.y),
+            None => Point::new(0.0, 0.0),
+        };
+
+        self.last_position = Some(position);
+
+        RawMouseMoveEvent { delta }
+    }
+
+    /// Locks the pointer to `widget`, per a `RequestPointerLockEvent`.
+    pub fn lock(&mut self, widget: Entity) {
+        self.locked_widget = Some(widget);
+    }
+
+    /// Releases the pointer lock, per a `RequestPointerUnlockEvent` or a button-up while
+    /// locked.
+    pub fn unlock(&mut self) {
+        self.locked_widget = None;
+    }
+
+    /// Returns the widget the pointer is currently locked to, if any.
+    pub fn locked_widget(&self) -> Option<Entity> {
+        self.locked_widget
+    }
 }
 
 /// Defines the mouse handler function.
@@ -110,6 +766,13 @@ pub type MouseHandlerFunction = dyn Fn(&mut StatesContext, Mouse) -> bool + 'sta
 //// Defines a position based event handler.
 pub type PositionHandlerFunction = dyn Fn(&mut StatesContext, Point) -> bool + 'static;
 
+/// Defines a click event handler function, receiving the click position and click count.
+pub type ClickHandlerFunction = dyn Fn(&mut StatesContext, Click) -> bool + 'static;
+
+/// Defines a mouse move handler function, receiving the position together with the held
+/// buttons and modifiers, so handlers can implement drag-select and modifier-gated moves.
+pub type MouseMoveHandlerFunction = dyn Fn(&mut StatesContext, MouseMove) -> bool + 'static;
+
 /// Defines the global bouse handler function.
 pub type GlobalMouseHandlerFunction = dyn Fn(&mut StatesContext, Mouse) + 'static;
 
@@ -137,6 +800,72 @@ impl EventHandler for ClickEventHandler {
     }
 }
 
+/// Used to handle double-click events (`count == 2`). Could be attached to a widget.
+pub struct DoubleClickEventHandler {
+    handler: Rc<ClickHandlerFunction>,
+}
+
+impl Into<Rc<dyn EventHandler>> for DoubleClickEventHandler {
+    fn into(self) -> Rc<dyn EventHandler> {
+        Rc::new(self)
+    }
+}
+
+impl EventHandler for DoubleClickEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event.downcast_ref::<ClickEvent>().ok().map_or(false, |event| {
+            if event.count != 2 {
+                return false;
+            }
+
+            (self.handler)(
+                state_context,
+                Click {
+                    position: event.position,
+                    count: event.count,
+                },
+            )
+        })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ClickEvent>()
+    }
+}
+
+/// Used to handle triple-click events (`count == 3`). Could be attached to a widget.
+pub struct TripleClickEventHandler {
+    handler: Rc<ClickHandlerFunction>,
+}
+
+impl Into<Rc<dyn EventHandler>> for TripleClickEventHandler {
+    fn into(self) -> Rc<dyn EventHandler> {
+        Rc::new(self)
+    }
+}
+
+impl EventHandler for TripleClickEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event.downcast_ref::<ClickEvent>().ok().map_or(false, |event| {
+            if event.count != 3 {
+                return false;
+            }
+
+            (self.handler)(
+                state_context,
+                Click {
+                    position: event.position,
+                    count: event.count,
+                },
+            )
+        })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ClickEvent>()
+    }
+}
+
 /// Used to handle mouse down events. Could be attached to a widget.
 #[derive(IntoHandler)]
 pub struct MouseDownEventHandler {
@@ -149,7 +878,16 @@ impl EventHandler for MouseDownEventHandler {
             .downcast_ref::<MouseDownEvent>()
             .ok()
             .map_or(false, |event| {
-                (self.handler)(state_context, Mouse { button: event.button, x: event.x, y: event.y })
+                (self.handler)(
+                    state_context,
+                    Mouse {
+                        button: event.button,
+                        x: event.x,
+                        y: event.y,
+                        buttons: event.buttons,
+                        modifiers: event.modifiers,
+                    },
+                )
             })
     }
 
@@ -170,7 +908,16 @@ impl EventHandler for GlobalMouseUpEventHandler {
             .downcast_ref::<GlobalMouseUpEvent>()
             .ok()
             .map_or(false, |event| {
-                (self.handler)(state_context,  Mouse { button: event.button, x: event.x, y: event.y });
+                (self.handler)(
+                    state_context,
+                    Mouse {
+                        button: event.button,
+                        x: event.x,
+                        y: event.y,
+                        buttons: event.buttons,
+                        modifiers: event.modifiers,
+                    },
+                );
                 false
             })
     }
@@ -192,7 +939,16 @@ impl EventHandler for MouseUpEventHandler {
             .downcast_ref::<MouseUpEvent>()
             .ok()
             .map_or(false, |event| {
-                (self.handler)(state_context,  Mouse { button: event.button, x: event.x, y: event.y })
+                (self.handler)(
+                    state_context,
+                    Mouse {
+                        button: event.button,
+                        x: event.x,
+                        y: event.y,
+                        buttons: event.buttons,
+                        modifiers: event.modifiers,
+                    },
+                )
             })
     }
 
@@ -204,7 +960,7 @@ impl EventHandler for MouseUpEventHandler {
 /// Used to handle mouse down events. Could be attached to a widget.
 #[derive(IntoHandler)]
 pub struct MouseMoveEventHandler {
-    handler: Rc<PositionHandlerFunction>,
+    handler: Rc<MouseMoveHandlerFunction>,
 }
 
 impl EventHandler for MouseMoveEventHandler {
@@ -213,7 +969,15 @@ impl EventHandler for MouseMoveEventHandler {
             .downcast_ref::<MouseMoveEvent>()
             .ok()
             .map_or(false, |event| {
-                (self.handler)(state_context, Point::new(event.x, event.y))
+                (self.handler)(
+                    state_context,
+                    MouseMove {
+                        x: event.x,
+                        y: event.y,
+                        buttons: event.buttons,
+                        modifiers: event.modifiers,
+                    },
+                )
             })
     }
 
@@ -222,6 +986,225 @@ impl EventHandler for MouseMoveEventHandler {
     }
 }
 
+/// Used to handle mouse enter events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct MouseEnterEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for MouseEnterEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<MouseEnterEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, Point::new(event.x, event.y))
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<MouseEnterEvent>()
+    }
+}
+
+/// Used to handle mouse leave events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct MouseLeaveEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for MouseLeaveEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<MouseLeaveEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, Point::new(event.x, event.y))
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<MouseLeaveEvent>()
+    }
+}
+
+/// Used to handle mouse-down-outside events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct MouseDownOutEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for MouseDownOutEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<MouseDownOutEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, Point::new(event.x, event.y))
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<MouseDownOutEvent>()
+    }
+}
+
+/// Used to handle click-outside events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct ClickOutEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for ClickOutEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<ClickOutEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, Point::new(event.x, event.y))
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ClickOutEvent>()
+    }
+}
+
+/// Defines a drag event handler function, receiving the current phase of the gesture.
+pub type DragHandlerFunction = dyn Fn(&mut StatesContext, Drag) -> bool + 'static;
+
+/// Defines a drop event handler function, receiving the drop position, the widget the
+/// payload originated from (`None` for file drops coming from outside the application), and
+/// the payload.
+pub type DropHandlerFunction =
+    dyn Fn(&mut StatesContext, Point, Option<Entity>, DropPayload) -> bool + 'static;
+
+/// Defines a predicate deciding whether a widget accepts a given drop payload.
+pub type AcceptsDropFunction = dyn Fn(&DropPayload) -> bool + 'static;
+
+/// Used to handle drag gesture events (`DragStartEvent`/`DragMoveEvent`/`DragEndEvent`).
+/// Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DragEventHandler {
+    handler: Rc<DragHandlerFunction>,
+}
+
+impl EventHandler for DragEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<DragStartEvent>() {
+            return (self.handler)(
+                state_context,
+                Drag::Start {
+                    position: event.position,
+                    source: event.source,
+                },
+            );
+        }
+
+        if let Ok(event) = event.downcast_ref::<DragMoveEvent>() {
+            return (self.handler)(
+                state_context,
+                Drag::Move {
+                    position: event.position,
+                    source: event.source,
+                },
+            );
+        }
+
+        if let Ok(event) = event.downcast_ref::<DragEndEvent>() {
+            return (self.handler)(
+                state_context,
+                Drag::End {
+                    position: event.position,
+                    source: event.source,
+                },
+            );
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragStartEvent>()
+            || event.is_type::<DragMoveEvent>()
+            || event.is_type::<DragEndEvent>()
+    }
+}
+
+/// Used to handle drop events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DropEventHandler {
+    handler: Rc<DropHandlerFunction>,
+}
+
+impl EventHandler for DropEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event.downcast_ref::<DropEvent>().ok().map_or(false, |event| {
+            (self.handler)(
+                state_context,
+                event.position,
+                event.source,
+                event.payload.clone(),
+            )
+        })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DropEvent>()
+    }
+}
+
+/// Marks a widget as a valid drop target, deciding per-payload acceptance with `predicate`.
+///
+/// Unlike the other handlers in this module, `AcceptsDropHandler` never handles a `DropEvent`
+/// itself: `handles_event` always returns `false`, so it cannot compete with
+/// `DropEventHandler` over the same event during normal dispatch. Instead, the router looks
+/// up a widget's `AcceptsDropHandler` (if any) and calls `accepts` *before* constructing and
+/// dispatching the `DropEvent`, skipping the drop (and any `on_drop` handler) entirely when
+/// it returns `false`.
+#[derive(IntoHandler)]
+pub struct AcceptsDropHandler {
+    predicate: Rc<AcceptsDropFunction>,
+}
+
+impl AcceptsDropHandler {
+    /// Returns `true` if this widget accepts `payload` as a drop.
+    pub fn accepts(&self, payload: &DropPayload) -> bool {
+        (self.predicate)(payload)
+    }
+}
+
+impl EventHandler for AcceptsDropHandler {
+    fn handle_event(&self, _state_context: &mut StatesContext, _event: &EventBox) -> bool {
+        false
+    }
+
+    fn handles_event(&self, _event: &EventBox) -> bool {
+        false
+    }
+}
+
+/// Used to handle raw (relative) mouse move events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct RawMouseMoveEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for RawMouseMoveEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<RawMouseMoveEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.delta)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<RawMouseMoveEvent>()
+    }
+}
+
 /// Used to handle scroll events. Could be attached to a widget.
 #[derive(IntoHandler)]
 pub struct ScrollEventHandler {
@@ -251,6 +1234,30 @@ pub trait MouseHandler: Sized + Widget {
         })
     }
 
+    /// Insert a double-click handler, fired only when a click completes a click sequence of
+    /// exactly two clicks. The existing `on_click` handler still fires for every click,
+    /// regardless of count.
+    fn on_double_click<H: Fn(&mut StatesContext, Click) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DoubleClickEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Insert a triple-click handler, fired only when a click completes a click sequence of
+    /// exactly three clicks. The existing `on_click` handler still fires for every click,
+    /// regardless of count.
+    fn on_triple_click<H: Fn(&mut StatesContext, Click) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(TripleClickEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
     /// Insert a mouse down handler.
     fn on_mouse_down<H: Fn(&mut StatesContext, Mouse) -> bool + 'static>(self, handler: H) -> Self {
         self.insert_handler(MouseDownEventHandler {
@@ -272,13 +1279,100 @@ pub trait MouseHandler: Sized + Widget {
         })
     }
 
-    /// Insert a mouse move handler.
-    fn on_mouse_move<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
+    /// Insert a mouse move handler. The handler receives the held buttons and modifiers
+    /// together with the position, so it can implement drag-select (checking the primary
+    /// button) or modifier-gated moves without tracking that state separately.
+    fn on_mouse_move<H: Fn(&mut StatesContext, MouseMove) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
         self.insert_handler(MouseMoveEventHandler {
             handler: Rc::new(handler),
         })
     }
 
+    /// Insert a mouse enter handler, fired when the pointer enters the widget's bounds.
+    fn on_mouse_enter<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(MouseEnterEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Insert a mouse leave handler, fired when the pointer leaves the widget's bounds, the
+    /// window, or the window loses focus.
+    fn on_mouse_leave<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(MouseLeaveEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Insert a mouse-down-outside handler, fired when a mouse button is pressed outside of
+    /// this widget's bounds. Useful for dismissing popups, dropdowns, and context menus.
+    fn on_mouse_down_out<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(MouseDownOutEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Insert a click-outside handler, fired when a click completes outside of this widget's
+    /// bounds. Useful for dismissing popups, dropdowns, and context menus.
+    fn on_click_out<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(ClickOutEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Insert a drag handler, receiving the phase (`Drag::Start`/`Drag::Move`/`Drag::End`) of
+    /// a drag gesture starting on this widget.
+    fn on_drag<H: Fn(&mut StatesContext, Drag) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(DragEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Insert a drop handler, fired when a drag gesture ends over this widget and the
+    /// payload was accepted.
+    fn on_drop<H: Fn(&mut StatesContext, Point, Option<Entity>, DropPayload) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DropEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Marks this widget as a valid drop target, deciding per-payload acceptance with
+    /// `predicate`.
+    fn accepts_drop<H: Fn(&DropPayload) -> bool + 'static>(self, predicate: H) -> Self {
+        self.insert_handler(AcceptsDropHandler {
+            predicate: Rc::new(predicate),
+        })
+    }
+
+    /// Insert a raw mouse move handler, receiving the frame-to-frame pointer delta instead of
+    /// an absolute position. Keeps reporting deltas while the pointer is locked to this
+    /// widget via `RequestPointerLockEvent`.
+    fn on_raw_mouse_move<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(RawMouseMoveEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
     /// Insert a mouse up handler.
     fn on_scroll<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
         self.insert_handler(ScrollEventHandler {
@@ -286,3 +1380,186 @@ pub trait MouseHandler: Sized + Widget {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_mouse_hover_transition_reports_enter_and_leave() {
+        assert_eq!(check_mouse_hover_transition(false, true), Some(true));
+        assert_eq!(check_mouse_hover_transition(true, false), Some(false));
+        assert_eq!(check_mouse_hover_transition(false, false), None);
+        assert_eq!(check_mouse_hover_transition(true, true), None);
+    }
+
+    #[test]
+    fn hover_tracker_reports_transition_only_once() {
+        let mut tracker = HoverTracker::new();
+        let widget = Entity(0);
+
+        assert_eq!(tracker.moved(widget, true), Some(true));
+        assert_eq!(tracker.moved(widget, true), None);
+        assert_eq!(tracker.moved(widget, false), Some(false));
+    }
+
+    #[test]
+    fn next_click_count_increments_within_time_and_distance() {
+        let origin = Point::new(10.0, 10.0);
+        let nearby = Point::new(12.0, 10.0);
+
+        assert_eq!(next_click_count(origin, 1, 200, nearby), 2);
+    }
+
+    #[test]
+    fn next_click_count_resets_after_timeout() {
+        let origin = Point::new(10.0, 10.0);
+
+        assert_eq!(
+            next_click_count(origin, 2, CLICK_COUNT_TIMEOUT_MILLIS + 1, origin),
+            1
+        );
+    }
+
+    #[test]
+    fn next_click_count_resets_past_distance_threshold() {
+        let origin = Point::new(10.0, 10.0);
+        let far = Point::new(10.0 + CLICK_COUNT_DISTANCE_THRESHOLD + 1.0, 10.0);
+
+        assert_eq!(next_click_count(origin, 2, 0, far), 1);
+    }
+
+    #[test]
+    fn click_tracker_counts_rapid_clicks_at_the_same_position() {
+        let mut tracker = ClickTracker::new();
+        let position = Point::new(5.0, 5.0);
+
+        assert_eq!(tracker.register(position), 1);
+        assert_eq!(tracker.register(position), 2);
+        assert_eq!(tracker.register(position), 3);
+    }
+
+    #[test]
+    fn click_tracker_resets_for_a_distant_click() {
+        let mut tracker = ClickTracker::new();
+
+        assert_eq!(tracker.register(Point::new(0.0, 0.0)), 1);
+        assert_eq!(
+            tracker.register(Point::new(CLICK_COUNT_DISTANCE_THRESHOLD + 10.0, 0.0)),
+            1
+        );
+    }
+
+    #[test]
+    fn hover_tracker_clear_returns_only_hovered_widgets() {
+        let mut tracker = HoverTracker::new();
+        let hovered = Entity(0);
+        let not_hovered = Entity(1);
+
+        tracker.moved(hovered, true);
+        tracker.moved(not_hovered, false);
+
+        assert_eq!(tracker.clear(), vec![hovered]);
+        assert!(tracker.clear().is_empty());
+    }
+
+    #[test]
+    fn mouse_down_out_targets_skips_widgets_the_pointer_is_over() {
+        let inside = Entity(0);
+        let outside = Entity(1);
+        let hit_results = [(inside, true), (outside, false)];
+
+        let targets = mouse_down_out_targets(Point::new(1.0, 2.0), &hit_results);
+
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].0, outside);
+    }
+
+    #[test]
+    fn click_out_targets_skips_widgets_the_pointer_is_over() {
+        let inside = Entity(0);
+        let outside = Entity(1);
+        let hit_results = [(inside, true), (outside, false)];
+
+        let targets = click_out_targets(Point::new(1.0, 2.0), &hit_results);
+
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].0, outside);
+    }
+
+    #[test]
+    fn drag_gesture_tracker_stays_idle_below_threshold() {
+        let mut tracker = DragGestureTracker::new();
+        let source = Entity(0);
+        let origin = Point::new(0.0, 0.0);
+
+        tracker.press(source, origin);
+
+        assert!(tracker
+            .moved(Point::new(DRAG_START_THRESHOLD - 1.0, 0.0))
+            .is_none());
+    }
+
+    #[test]
+    fn drag_gesture_tracker_starts_past_threshold_then_moves_and_ends() {
+        let mut tracker = DragGestureTracker::new();
+        let source = Entity(0);
+        let origin = Point::new(0.0, 0.0);
+
+        tracker.press(source, origin);
+
+        let start = tracker.moved(Point::new(DRAG_START_THRESHOLD + 1.0, 0.0));
+        assert!(matches!(start, Some(DragGestureMove::Start(_))));
+
+        let moved = tracker.moved(Point::new(DRAG_START_THRESHOLD + 2.0, 0.0));
+        assert!(matches!(moved, Some(DragGestureMove::Move(_))));
+
+        let end = tracker.released(Point::new(DRAG_START_THRESHOLD + 2.0, 0.0));
+        assert!(end.is_some());
+        assert_eq!(end.unwrap().source, source);
+    }
+
+    #[test]
+    fn drag_gesture_tracker_released_before_threshold_is_not_a_drag() {
+        let mut tracker = DragGestureTracker::new();
+        let source = Entity(0);
+        let origin = Point::new(0.0, 0.0);
+
+        tracker.press(source, origin);
+
+        assert!(tracker.released(origin).is_none());
+    }
+
+    #[test]
+    fn pointer_motion_tracker_reports_zero_delta_on_first_move() {
+        let mut tracker = PointerMotionTracker::new();
+
+        let event = tracker.moved(Point::new(10.0, 10.0));
+
+        assert_eq!(event.delta, Point::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn pointer_motion_tracker_reports_delta_on_subsequent_moves() {
+        let mut tracker = PointerMotionTracker::new();
+
+        tracker.moved(Point::new(10.0, 10.0));
+        let event = tracker.moved(Point::new(13.0, 8.0));
+
+        assert_eq!(event.delta, Point::new(3.0, -2.0));
+    }
+
+    #[test]
+    fn pointer_motion_tracker_locked_widget_round_trips() {
+        let mut tracker = PointerMotionTracker::new();
+        let widget = Entity(0);
+
+        assert_eq!(tracker.locked_widget(), None);
+
+        tracker.lock(widget);
+        assert_eq!(tracker.locked_widget(), Some(widget));
+
+        tracker.unlock();
+        assert_eq!(tracker.locked_widget(), None);
+    }
+}